@@ -1,6 +1,7 @@
 /// 定义类 C 的枚举类型，并通过一些函数将外部不可控数据安全地转换为枚举类型。
 ///
-/// ⚠️ 只是适用于含单个 primitive 类型字段的结构体。
+/// ⚠️ 默认生成的 `From<枚举>` 只适用于含单个 primitive 类型字段的结构体；含其他字段时，
+/// 在头部加上 `default`（以 `Default::default()` 填充其余字段）或 `no_from`（不生成 `From`）。
 ///
 /// 示例：
 ///
@@ -42,103 +43,2256 @@
 ///     }
 /// }
 /// ```
+///
+/// 枚举及各变体上的属性（文档、`#[non_exhaustive]`、`#[deprecated]` 等）会原样转发：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     /// 问候语。
+///     #[non_exhaustive]
+///     pub Hellos [Hello::data: u8] {
+///         /// 第一个值
+///         V0: 0,
+///         #[deprecated]
+///         V1: 1,
+///     }
+/// }
+///
+/// match (Hello { data: 0 }).as_enum() {
+///     Wellknown(Hellos::V0) => {}
+///     #[allow(deprecated)]
+///     Wellknown(Hellos::V1) => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// 枚举本身也实现了与原始值之间的 `TryFrom`、`From`：
+///
+/// ```
+/// use const_enum::const_enum;
+/// use core::convert::TryFrom;
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 13,
+///     }
+/// }
+///
+/// assert_eq!(Hellos::try_from(13), Ok(Hellos::V1));
+/// assert_eq!(Hellos::try_from(33), Err(33));
+/// assert_eq!(u8::from(Hellos::V1), 13);
+/// ```
+///
+/// 由枚举构造结构体：
+///
+/// ```
+/// use const_enum::const_enum;
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 13,
+///     }
+/// }
+///
+/// assert_eq!(Hello::from(Hellos::V1).data, 13);
+/// let hello: Hello = Hellos::V0.into();
+/// assert_eq!(hello.data, 0);
+/// ```
+///
+/// `from_raw`、`to_raw` 为 `const fn`，可用于常量表：
+///
+/// ```
+/// use const_enum::{const_enum, ConstEnum, Wellknown, Unknown};
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 12,
+///     }
+/// }
+///
+/// const DECODED: [ConstEnum<Hellos, u8>; 2] = [Hellos::from_raw(12), Hellos::from_raw(23)];
+/// assert_eq!(DECODED, [Wellknown(Hellos::V1), Unknown(23)]);
+/// const RAW: u8 = Hellos::V1.to_raw();
+/// assert_eq!(RAW, 12);
+/// ```
+///
+/// 变体元信息：
+///
+/// ```
+/// use const_enum::{const_enum, Wellknown};
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 1,
+///         V2: 12,
+///     }
+/// }
+///
+/// assert_eq!(Hellos::COUNT, 3);
+/// assert_eq!(Hellos::VARIANTS, &[Hellos::V0, Hellos::V1, Hellos::V2]);
+/// assert_eq!(Hellos::VALUES, &[0, 1, 12]);
+/// assert_eq!(Hellos::V2.name(), "V2");
+/// for v in Hellos::iter() {
+///     assert_eq!(Hellos::from_raw(v.to_raw()), Wellknown(v));
+/// }
+/// ```
+///
+/// `Display` 输出变体名称，`FromStr` 接受变体名称或十进制、`0x` 前缀的十六进制原始值：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, ParseEnumError};
+///
+/// // 生成的代码不受同名别名影响。
+/// #[allow(dead_code)]
+/// type Result<T> = core::result::Result<T, ()>;
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 12,
+///         V2: 22,
+///     }
+/// }
+///
+/// assert_eq!(Hellos::V1.to_string(), "V1");
+/// assert_eq!(Hello { data: 33 }.as_enum().to_string(), "Unknown(0x21)");
+/// assert_eq!("V1".parse(), Ok(Hellos::V1));
+/// assert_eq!("0x16".parse(), Ok(Hellos::V2));
+/// assert_eq!("12".parse(), Ok(Hellos::V1));
+/// assert_eq!(Hellos::from_str_ignore_case("v0"), Ok(Hellos::V0));
+/// assert_eq!("33".parse::<Hellos>(), Err(ParseEnumError::Unknown));
+/// assert_eq!("V9".parse::<Hellos>(), Err(ParseEnumError::Invalid));
+/// ```
+///
+/// `SetEnum` 改写已有结构体中的字段：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, SetEnum, Wellknown};
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data: u8] {
+///         V0: 0,
+///         V1: 22,
+///     }
+/// }
+///
+/// let mut hello = Hello { data: 0 };
+/// hello.set_enum(Hellos::V1);
+/// assert_eq!(hello.data, 22);
+/// assert_eq!(hello.as_enum(), Wellknown(Hellos::V1));
+/// ```
+///
+/// `$Struct($SuperStruct)` 形式会额外为 `$SuperStruct` 生成 `From<$EnumType>`，
+/// `$SuperStruct` 需含同名字段：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct Reg {
+///     pub val: u16,
+/// }
+/// pub struct RegView {
+///     pub val: u16,
+/// }
+///
+/// const_enum! {
+///     pub Mode [Reg(RegView)::val: u16] {
+///         Off: 0,
+///         On: 0x100,
+///     }
+/// }
+///
+/// assert_eq!(Reg::from(Mode::On).val, 0x100);
+/// assert_eq!(RegView::from(Mode::Off).val, 0);
+/// let view: RegView = Mode::On.into();
+/// assert_eq!(view.val, 0x100);
+/// assert_eq!(Reg { val: 0x100 }.as_enum(), Wellknown(Mode::On));
+/// ```
+///
+/// 括号内也可以列出多个目标，以逗号分隔。目标写作 `Target` 时沿用 `$Field`，写作
+/// `Target::field` 时使用指定的字段；末尾加上 `as_enum` 则同时为该目标实现 `AsEnum`、
+/// `SetEnum` 等：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct Reg {
+///     pub val: u16,
+/// }
+/// pub struct Request {
+///     pub mode: u16,
+/// }
+/// pub struct Response {
+///     pub current_mode: u16,
+/// }
+///
+/// const_enum! {
+///     pub Mode [Reg(Request::mode, Response::current_mode as_enum)::val: u16] {
+///         Off: 0,
+///         On: 0x100,
+///     }
+/// }
+///
+/// assert_eq!(Request::from(Mode::On).mode, 0x100);
+/// assert_eq!(Response { current_mode: 0 }.as_enum(), Wellknown(Mode::Off));
+/// assert_eq!(Reg { val: 0x100 }.as_enum(), Wellknown(Mode::On));
+/// ```
+///
+/// 变体的值可以是任意常量表达式，例如引用其他 crate 中的常量：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// mod vendor {
+///     pub const BASE: u8 = 0x20;
+/// }
+///
+/// pub struct Cmd {
+///     pub op: u8,
+/// }
+///
+/// const_enum! {
+///     pub Op [Cmd::op: u8] {
+///         Reset: vendor::BASE,
+///         Read: vendor::BASE + 1,
+///         Flag: 1 << 4,
+///     }
+/// }
+///
+/// assert_eq!(Cmd { op: 0x21 }.as_enum(), Wellknown(Op::Read));
+/// assert_eq!(Op::VALUES, &[0x20, 0x21, 0x10]);
+/// ```
+///
+/// 值表达式中的常量即使与某个变体同名，也按其本来的含义求值：
+///
+/// ```
+/// use const_enum::{const_enum, Wellknown};
+///
+/// mod vendor {
+///     pub const READ: u8 = 7;
+/// }
+/// use vendor::*;
+///
+/// const BASE: u8 = 5;
+///
+/// pub struct Cmd {
+///     pub op: u8,
+/// }
+///
+/// const_enum! {
+///     #[allow(non_camel_case_types)]
+///     pub Op [Cmd::op: u8] {
+///         BASE: 0,
+///         Next: BASE + 1,
+///         READ: READ,
+///     }
+/// }
+///
+/// assert_eq!(Op::Next.to_raw(), 6);
+/// assert_eq!(Op::from_raw(6), Wellknown(Op::Next));
+/// assert_eq!(Op::from_raw(7), Wellknown(Op::READ));
+/// assert_eq!(Op::from_raw(0), Wellknown(Op::BASE));
+/// ```
+///
+/// `Alias = Name` 为已有变体声明别名（生成同名关联常量），解码时总是得到原变体。
+/// 两个变体的值相同则会编译失败，并分别指出这两个变体：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct Frame {
+///     pub kind: u8,
+/// }
+///
+/// const_enum! {
+///     pub Kind [Frame::kind: u8] {
+///         Data: 0,
+///         Ack: 1,
+///         /// 旧版协议中的名称
+///         Confirm = Ack,
+///     }
+/// }
+///
+/// assert_eq!(Frame { kind: 1 }.as_enum(), Wellknown(Kind::Ack));
+/// assert!(matches!(Frame { kind: 1 }.as_enum(), Wellknown(Kind::Confirm)));
+/// ```
+///
+/// ```compile_fail
+/// use const_enum::const_enum;
+///
+/// pub struct Frame {
+///     pub kind: u8,
+/// }
+///
+/// const_enum! {
+///     pub Kind [Frame::kind: u8] {
+///         Ack: 1,
+///         Confirm: 1, // 编译错误：variant `Kind::Confirm` (1) shares its value with another variant
+///     }
+/// }
+/// ```
+///
+/// `Name(类型): Low..=High` 声明区间变体：区间内的任意值都解码为该变体并保留原始值，
+/// 写回时原样还原。括号中的类型须与字段类型相同。区间的上下界可以是任意常量表达式。
+/// 区间之间、区间与其他变体的值都不能重叠。
+/// 区间变体不计入 `VARIANTS`、`VALUES`、`COUNT` 与 `iter`：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown, Unknown};
+///
+/// mod vendor {
+///     pub const LO: u8 = 0x80;
+/// }
+///
+/// pub struct Cmd {
+///     pub op: u8,
+/// }
+///
+/// const_enum! {
+///     pub Op [Cmd::op: u8] {
+///         Nop: 0,
+///         Read: 1,
+///         Vendor(u8): vendor::LO..=0xFF,
+///     }
+/// }
+///
+/// assert_eq!(Cmd { op: 0x9A }.as_enum(), Wellknown(Op::Vendor(0x9A)));
+/// assert_eq!(Cmd { op: 0x20 }.as_enum(), Unknown(0x20));
+/// assert_eq!(Op::Vendor(0x9A).to_raw(), 0x9A);
+/// assert_eq!(Cmd::from(Op::Vendor(0xC0)).op, 0xC0);
+/// assert_eq!(Op::Vendor(0x9A).to_string(), "Vendor(0x9a)");
+/// assert_eq!(Op::Vendor(0x9A).name(), "Vendor");
+/// assert_eq!(Op::COUNT, 2);
+/// assert_eq!(Op::VARIANTS, &[Op::Nop, Op::Read]);
+/// ```
+///
+/// 元组结构体以下标指定字段：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct Opcode(pub u8);
+///
+/// const_enum! {
+///     pub Op [Opcode::0: u8] {
+///         Nop: 0,
+///         Jmp: 0x10,
+///     }
+/// }
+///
+/// assert_eq!(Opcode(0x10).as_enum(), Wellknown(Op::Jmp));
+/// assert_eq!(Opcode::from(Op::Nop).0, 0);
+/// ```
+///
+/// 没有现成的结构体时，`[struct 名称(类型)]` 会生成一个 `#[repr(transparent)]` 的开放枚举
+/// 新类型：它能无损保存任意原始值，已知值以同名关联常量表示并可直接用于 `match`：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// const_enum! {
+///     pub Color [struct RawColor(u8)] {
+///         Red: 1,
+///         Green: 2,
+///     }
+/// }
+///
+/// let raw = RawColor(2);
+/// match raw {
+///     RawColor::Red => unreachable!(),
+///     RawColor::Green => {}
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(raw.known(), Some(Color::Green));
+/// assert_eq!(raw.as_enum(), Wellknown(Color::Green));
+/// assert_eq!(RawColor(7).known(), None);
+/// assert_eq!(u8::from(RawColor::from(Color::Red)), 1);
+/// assert_eq!(RawColor(7).to_string(), "Unknown(0x7)");
+/// ```
+///
+/// 含多个字段的结构体：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, SetEnum, Wellknown};
+///
+/// #[derive(Default)]
+/// pub struct Descriptor {
+///     pub kind: u8,
+///     pub reserved: [u8; 3],
+///     pub len: u32,
+/// }
+/// pub struct Packet {
+///     pub kind: u8,
+///     pub payload: u64,
+/// }
+///
+/// const_enum! {
+///     pub DescKind [Descriptor::kind: u8, default] {
+///         Empty: 0,
+///         Data: 1,
+///     }
+///     pub PacketKind [Packet::kind: u8, no_from] {
+///         Ping: 0,
+///         Pong: 1,
+///     }
+/// }
+///
+/// let desc = Descriptor::from(DescKind::Data);
+/// assert_eq!((desc.kind, desc.len), (1, 0));
+/// let mut packet = Packet { kind: 0, payload: 42 };
+/// packet.set_enum(PacketKind::Pong);
+/// assert_eq!(packet.as_enum(), Wellknown(PacketKind::Pong));
+/// assert_eq!(packet.payload, 42);
+/// ```
+///
+/// 泛型及带生命周期参数的结构体在名称后写出泛型参数，约束以 `+` 连接，也可以写 `?Sized`
+/// 与 `const N: 类型`：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown};
+///
+/// pub struct FrameRef<'a, T: ?Sized> {
+///     pub kind: u8,
+///     pub body: &'a T,
+/// }
+/// #[derive(Default)]
+/// pub struct Header<T: Copy + Default> {
+///     pub kind: u16,
+///     pub extra: T,
+/// }
+/// pub struct Packet<const N: usize> {
+///     pub kind: u8,
+///     pub payload: [u8; N],
+/// }
+///
+/// const_enum! {
+///     pub FrameKind [FrameRef<'a, T: ?Sized>::kind: u8, no_from] {
+///         Data: 0,
+///         Ack: 1,
+///     }
+///     pub HeaderKind [Header<T: Copy + Default>::kind: u16, default] {
+///         Short: 0,
+///         Long: 1,
+///     }
+///     pub PacketKind [Packet<const N: usize>::kind: u8, no_from] {
+///         Ping: 0,
+///         Pong: 1,
+///     }
+/// }
+///
+/// let frame: FrameRef<[u8]> = FrameRef { kind: 1, body: &[] };
+/// assert_eq!(frame.as_enum(), Wellknown(FrameKind::Ack));
+/// let header: Header<u32> = HeaderKind::Long.into();
+/// assert_eq!(header.as_enum(), Wellknown(HeaderKind::Long));
+/// let packet = Packet { kind: 1, payload: [0; 4] };
+/// assert_eq!(packet.as_enum(), Wellknown(PacketKind::Pong));
+/// ```
+///
+/// 字段也可以是嵌套路径（`Outer::header.kind`）或读取方法（`Reg::read()`）。
+/// 嵌套路径生成 `From` 时需配合 `default`；方法是只读的，不生成 `From` 与 setter：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, SetEnum, Wellknown};
+///
+/// #[derive(Default)]
+/// pub struct Inner {
+///     pub kind: u8,
+/// }
+/// #[derive(Default)]
+/// pub struct Outer {
+///     pub header: Inner,
+///     pub len: u32,
+/// }
+/// pub struct Reg {
+///     raw: u32,
+/// }
+/// impl Reg {
+///     pub fn read(&self) -> u32 {
+///         self.raw
+///     }
+/// }
+///
+/// const_enum! {
+///     pub Kind [Outer::header.kind: u8, default] {
+///         Data: 0,
+///         Ack: 1,
+///     }
+///     pub Mode [Reg::read(): u32] {
+///         Off: 0,
+///         On: 5,
+///     }
+/// }
+///
+/// let mut outer = Outer::from(Kind::Ack);
+/// assert_eq!(outer.as_enum(), Wellknown(Kind::Ack));
+/// outer.set_enum(Kind::Data);
+/// assert_eq!(outer.header.kind, 0);
+/// assert_eq!(Reg { raw: 5 }.as_enum(), Wellknown(Mode::On));
+/// ```
+///
+/// 每个枚举都会为结构体实现 `AsEnumField<枚举>`，因此同一结构体可以含多个枚举字段。
+/// 在头部加上 `field_only` 则只解码该字段，不再实现 `AsEnum` 及 `From<枚举>`：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnumField, ConstEnum, Wellknown, Unknown};
+///
+/// pub struct Header {
+///     pub kind: u8,
+///     pub status: u16,
+/// }
+///
+/// const_enum! {
+///     pub Kind [Header::kind: u8, field_only] {
+///         Request: 0,
+///         Response: 1,
+///     }
+///     pub Status [Header::status: u16, field_only] {
+///         Ok: 200,
+///         NotFound: 404,
+///     }
+/// }
+///
+/// let header = Header { kind: 1, status: 500 };
+/// let kind: ConstEnum<Kind, _> = header.as_enum_field();
+/// assert_eq!(kind, Wellknown(Kind::Response));
+/// assert_eq!(AsEnumField::<Status>::as_enum_field(&header), Unknown(500));
+/// ```
+///
+/// `mask = 表达式, shift = 表达式` 将枚举绑定到字段中的若干位：解码时取
+/// `(字段 & mask) >> shift`，变体的值须能放入这些位中（编译期检查）。
+/// `SetEnumField` 与 `into_field` 只改写这些位：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnumField, SetEnumField, Wellknown};
+///
+/// pub struct Reg {
+///     pub bits: u32,
+/// }
+///
+/// const_enum! {
+///     pub Speed [Reg::bits: u32, mask = 0b1110, shift = 1, field_only] {
+///         Low: 0,
+///         Mid: 3,
+///         High: 7,
+///     }
+///     pub Enable [Reg::bits: u32, mask = 0b1, field_only] {
+///         Off: 0,
+///         On: 1,
+///     }
+/// }
+///
+/// let mut reg = Reg { bits: 0xF0 | 0b0111 };
+/// assert_eq!(AsEnumField::<Speed>::as_enum_field(&reg), Wellknown(Speed::Mid));
+/// assert_eq!(AsEnumField::<Enable>::as_enum_field(&reg), Wellknown(Enable::On));
+/// reg.set_enum_field(Speed::High);
+/// reg.set_enum_field(Enable::Off);
+/// assert_eq!(reg.bits, 0xF0 | 0b1110);
+/// assert_eq!(Speed::Low.into_field(0xFF), 0xF1);
+/// ```
+///
+/// 变体的值放不进 mask 给出的位、或 `shift` 不小于字段位宽时编译失败：
+///
+/// ```compile_fail
+/// use const_enum::const_enum;
+///
+/// pub struct Reg {
+///     pub bits: u32,
+/// }
+///
+/// const_enum! {
+///     pub Speed [Reg::bits: u32, mask = 0b1110, shift = 1, field_only] {
+///         Low: 0,
+///         High: 8,
+///     }
+/// }
+/// ```
+///
+/// 常量范围可以由 `|` 连接的多个区间组成。范围外的值即使与某个变体无关，也可以用
+/// `in_range` 与范围内未分配的值区分开：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Wellknown, Unknown};
+///
+/// pub struct Cmd {
+///     pub op: u8,
+/// }
+///
+/// const_enum! {
+///     pub Op [Cmd::op: u8, 0..=7 | 0x40..=0x4F] {
+///         Nop: 0,
+///         Read: 0x41,
+///     }
+/// }
+///
+/// assert_eq!(Cmd { op: 0x41 }.as_enum(), Wellknown(Op::Read));
+/// assert_eq!(Cmd { op: 3 }.as_enum(), Unknown(3));
+/// assert!(Op::in_range(3));
+/// assert!(!Op::in_range(0x20));
+/// ```
+///
+/// `classify` 据此给出三种结果：已知变体、范围内的保留值、范围外的值。已解码的
+/// `ConstEnum` 也可以用 `classify(枚举::in_range)` 细分：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Classified};
+///
+/// pub struct Cmd {
+///     pub op: u8,
+/// }
+///
+/// const_enum! {
+///     pub Op [Cmd::op: u8, 0..=22] {
+///         Nop: 0,
+///         Read: 1,
+///     }
+/// }
+///
+/// assert_eq!(Op::classify(1), Classified::Wellknown(Op::Read));
+/// assert_eq!(Op::classify(3), Classified::Reserved(3));
+/// assert_eq!(Op::classify(33), Classified::OutOfRange(33));
+/// assert_eq!(Cmd { op: 3 }.as_enum().classify(Op::in_range), Classified::Reserved(3));
+/// assert_eq!(Op::classify(33).to_string(), "OutOfRange(0x21)");
+/// ```
+///
+/// 在某个变体上标注 `#[default]` 会为枚举实现 `Default`，未知的原始值可用
+/// `as_enum_or_default`、`from_raw_or_default` 解码为该变体，`as_enum` 仍保留原始值：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, Unknown};
+///
+/// pub struct Frame {
+///     pub kind: u8,
+/// }
+///
+/// const_enum! {
+///     pub Kind [Frame::kind: u8] {
+///         Data: 0,
+///         /// 无法识别的类型按 `Ignore` 处理。
+///         #[default]
+///         Ignore: 1,
+///     }
+/// }
+///
+/// assert_eq!(Kind::default(), Kind::Ignore);
+/// assert_eq!(Frame { kind: 9 }.as_enum_or_default(), Kind::Ignore);
+/// assert_eq!(Frame { kind: 9 }.as_enum(), Unknown(9));
+/// const DECODED: Kind = Kind::from_raw_or_default(0);
+/// assert_eq!(DECODED, Kind::Data);
+/// ```
+///
+/// 有符号类型可以使用负数。`char`、`bool` 与 `&'static str` 不能作为 `#[repr]`，此时生成不带判别值的枚举，
+/// 由 `from_raw`、`to_raw` 查表转换，用法与整数类型相同。显示未知值时，有符号整数用十进制，
+/// 非整数类型同 `Debug`：
+///
+/// ```
+/// use const_enum::{const_enum, AsEnum, ParseEnumError, Wellknown, Unknown};
+///
+/// pub struct Level {
+///     pub db: i8,
+/// }
+/// pub struct Reply {
+///     pub code: char,
+/// }
+/// pub struct Command {
+///     pub name: &'static str,
+/// }
+///
+/// const_enum! {
+///     pub Gain [Level::db: i8, -12..=12] {
+///         Mute: -12,
+///         Unity: 0,
+///     }
+///     pub Ack [Reply::code: char] {
+///         Accept: 'A',
+///         Reject: 'N',
+///     }
+///     pub Verb [Command::name: &'static str] {
+///         Get: "GET",
+///         Put: "PUT",
+///     }
+/// }
+///
+/// assert_eq!(Level { db: -12 }.as_enum(), Wellknown(Gain::Mute));
+/// assert_eq!(Gain::Mute.to_raw(), -12);
+/// assert_eq!(Reply { code: 'N' }.as_enum(), Wellknown(Ack::Reject));
+/// assert_eq!(Reply { code: '?' }.as_enum(), Unknown('?'));
+/// assert_eq!(Reply::from(Ack::Accept).code, 'A');
+/// assert_eq!(Command { name: "PUT" }.as_enum(), Wellknown(Verb::Put));
+/// assert_eq!(Verb::Get.to_raw(), "GET");
+/// assert_eq!(Level { db: -5 }.as_enum().to_string(), "Unknown(-5)");
+/// assert_eq!("-5".parse::<Gain>(), Err(ParseEnumError::Unknown));
+/// assert_eq!("-12".parse::<Gain>(), Ok(Gain::Mute));
+/// assert_eq!(Gain::classify(-20).to_string(), "OutOfRange(-20)");
+/// assert_eq!(Reply { code: '?' }.as_enum().to_string(), "Unknown('?')");
+/// assert_eq!(Command { name: "DEL" }.as_enum().to_string(), r#"Unknown("DEL")"#);
+/// ```
+///
+/// 若给出了常量范围，每个变体的值都会在编译期检查是否落在其中某个区间内：
+///
+/// ```compile_fail
+/// use const_enum::const_enum;
+///
+/// pub struct Hello {
+///     pub data: u8,
+/// }
+///
+/// const_enum! {
+///     pub Hellos [Hello::data:u8, 0..=22] {
+///         V0: 0,
+///         V9: 40, // 编译错误：variant `Hellos::V9` (40) is outside the range 0..=22
+///     }
+/// }
+/// ```
 #[macro_export]
 macro_rules! const_enum {
     (
-        $($Vis:vis $EnumType:ident [$Struct:ident$(($SuperStruct:ident))?::$Field:ident: $FieldType:tt $(,$Low:literal ..= $Upper:literal)?] {
-            $(
-                $(#[$Doc:meta])?
-                $Variance:ident : $Value:literal
-            ),+ $(,)?
+        $($(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$($Header:tt)*] {
+            $($Body:tt)*
         })+
     ) => {
         $(
             $crate::const_enum!{
-                def_enum: $Vis $EnumType, $FieldType,
-                $($(#[$Doc])? $Variance $Value),+
+                parse_variants: {$(#[$EnumAttr])* $Vis $EnumType [$($Header)*]}
+                $($Body)*
+            }
+        )+
+    };
+    // 只含 `Name: value` 的变体列表直接展开，不受递归深度限制。
+    (parse_variants: $Head:tt
+        $(
+            $(#[$AttrName:tt $($Attr:tt)*])*
+            $Variance:ident : $Value:expr
+        ),+ $(,)?
+    ) => {
+        $crate::const_enum!{
+            find_default: ($) [$($([$AttrName $Variance])*)+]
+            emit: $Head
+            [$({$(#[$AttrName $($Attr)*])* $Variance $Value})+]
+            []
+            []
+        }
+    };
+    // 含别名或区间变体时，先把每个条目包成一组，再按有无 `= 目标`、`(类型)` 分拣，
+    // 展开步数与变体个数无关。
+    (parse_variants: $Head:tt
+        $(
+            $(#[$AttrName:tt $($Attr:tt)*])*
+            $Name:ident $(= $Target:ident)? $(($Inner:ty))? $(: $Value:expr)?
+        ),+ $(,)?
+    ) => {
+        $crate::const_enum!{
+            find_default: ($) [$($([$AttrName $Name])*)+]
+            split_variants: $Head
+            $({[$(#[$AttrName $($Attr)*])*] $Name [$($Target)?] [$($Inner)?] [$($Value)?]})+
+        }
+    };
+    (parse_variants: $Head:tt $($Rest:tt)*) => {
+        compile_error!(concat!(
+            "const_enum: expected `Name: value`, `Name(type): Low..=High` or `Alias = Name`, found `",
+            stringify!($($Rest)*), "`"
+        ));
+    };
+    // 找出带 `#[default]` 的变体：以各属性名为模式定义一个临时宏，再用 `default` 调用它，
+    // 展开步数与变体个数无关。找到时为枚举派生 `Default`，并以 `@default 变体` 选项传给后续步骤。
+    (find_default: ($d:tt) [$([$AttrName:tt $Name:ident])*] $Next:ident: $Head:tt $($Rest:tt)*) => {
+        macro_rules! __const_enum_find_default {
+            $(
+                ($AttrName $d($d Tail:tt)*) => {
+                    $crate::const_enum!{ with_default: $Name $d($d Tail)* }
+                };
+            )*
+            (default $d($d Tail:tt)*) => {
+                $crate::const_enum!{ $d($d Tail)* }
+            };
+        }
+        __const_enum_find_default!{ default $Next: $Head $($Rest)* }
+    };
+    (with_default: $Default:ident $Next:ident:
+        {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$($Header:tt)*]} $($Rest:tt)*
+    ) => {
+        $crate::const_enum!{
+            $Next: {#[derive(Default)] #[allow(deprecated)] $(#[$EnumAttr])* $Vis $EnumType [$($Header)*, @default $Default]}
+            $($Rest)*
+        }
+    };
+    // 别名与区间变体可由 `$Target`、`$Inner` 直接挑出；普通变体则在其余条目前加上标记，
+    // 由下一步以标记为界一次取出。
+    (split_variants: $Head:tt
+        $({$Attrs:tt $Name:ident [$($Target:ident)?] [$($Inner:ty)?] $Value:tt})+
+    ) => {
+        $(
+            $crate::const_enum!{
+                check_entry: $Name [$($Target)?] [$($Inner)?] $Value
+            }
+        )+
+        $crate::const_enum!{
+            collect_variants: $Head
+            [$($({$Attrs $Name $Target})?)+]
+            [$($({$Attrs $Name ($Inner) $Value})?)+]
+            $($([$Target])? $([$Inner])? {$Attrs $Name $Value})+ [] {}
+        }
+    };
+    (check_entry: $Name:ident [$Target:ident] [] []) => {};
+    (check_entry: $Name:ident [] [$Inner:ty] [$Range:expr]) => {};
+    (check_entry: $Name:ident [] [] [$Value:expr]) => {};
+    (check_entry: $Name:ident $Target:tt $Inner:tt $Value:tt) => {
+        compile_error!(concat!(
+            "const_enum: invalid variant `", stringify!($Name),
+            "`, expected `Name: value`, `Name(type): Low..=High` or `Alias = Name`"
+        ));
+    };
+    (collect_variants: {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident $Header:tt} $Aliases:tt $Ranges:tt
+        $([$($Marker:tt)*] {$($Other:tt)*})+
+    ) => {
+        compile_error!(concat!(
+            "const_enum: `", stringify!($EnumType), "` needs at least one `Name: value` variant"
+        ));
+    };
+    (collect_variants: $Head:tt
+        [$({[$($AliasAttr:tt)*] $Alias:ident $Target:ident})*]
+        [$({[$($RangedAttr:tt)*] $Ranged:ident ($Inner:ty) [$Range:expr]})*]
+        $($({[$($Attr:tt)*] $Variance:ident [$Value:expr]})* [$($Marker:tt)*] {$($Other:tt)*})+
+    ) => {
+        $crate::const_enum!{
+            emit: $Head
+            [$($({$($Attr)* $Variance $Value})*)+]
+            [$({$($AliasAttr)* $Alias $Target})*]
+            [$({$($RangedAttr)* $Ranged ($Inner) $Range})*]
+        }
+    };
+    // 其余情况已由 `check_entry` 报错。
+    (collect_variants: $($Rest:tt)*) => {};
+    // `[struct 名称(类型)]`：生成可无损保存任意值的开放枚举新类型，再按 `名称::0` 处理。
+    (emit:
+        {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [struct $Open:ident($FieldType:tt) $(, $($Opt:tt)+)?]}
+        $Variants:tt $Aliases:tt $Ranges:tt
+    ) => {
+        $crate::const_enum!{
+            kind: $FieldType,
+            open_struct: {$Vis $Open, $EnumType, $FieldType} $Variants $Aliases $Ranges
+        }
+        $crate::const_enum!{
+            emit: {$(#[$EnumAttr])* $Vis $EnumType [$Open::0: $FieldType $(, $($Opt)+)?]}
+            $Variants $Aliases $Ranges
+        }
+    };
+    // `&'static str` 不是单个 token，先转换为类型片段再按一般情况处理。
+    (emit:
+        {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$Struct:ident $(<$($Param:tt $($ParamName:ident)? $(: $Bound:tt $($BoundName:ident)? $(::$BoundPath:ident)* $(<$($BoundArg:ty),+>)? $(+ $More:tt $($MoreName:ident)? $(::$MorePath:ident)* $(<$($MoreArg:ty),+>)?)*)?),+>)? $(($($SuperStruct:ident $(::$($SuperPath:tt).+)? $($Mark:ident)?),+ $(,)?))?::$($Path:tt).+ $(($($Args:tt)*))?: &'static str $(, $($Opt:tt)+)?]}
+        $Variants:tt $Aliases:tt $Ranges:tt
+    ) => {
+        $crate::const_enum!{
+            emit_ty: {$(#[$EnumAttr])* $Vis $EnumType}
+            [$Struct $(<$($Param $($ParamName)? $(: $Bound $($BoundName)? $(::$BoundPath)* $(<$($BoundArg),+>)? $(+ $More $($MoreName)? $(::$MorePath)* $(<$($MoreArg),+>)?)*)?),+>)? $(($($SuperStruct $(::$($SuperPath).+)? $($Mark)?),+))?::$($Path).+ $(($($Args)*))?]
+            [$(, $($Opt)+)?]
+            $Variants $Aliases $Ranges
+            &'static str
+        }
+    };
+    (emit_ty: {$($Enum:tt)*} [$($Header:tt)*] [$($Opt:tt)*] $Variants:tt $Aliases:tt $Ranges:tt $FieldType:ty) => {
+        $crate::const_enum!{
+            emit: {$($Enum)* [$($Header)*: $FieldType $($Opt)*]}
+            $Variants $Aliases $Ranges
+        }
+    };
+    (emit:
+        {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$Struct:ident $(<$($Param:tt $($ParamName:ident)? $(: $Bound:tt $($BoundName:ident)? $(::$BoundPath:ident)* $(<$($BoundArg:ty),+>)? $(+ $More:tt $($MoreName:ident)? $(::$MorePath:ident)* $(<$($MoreArg:ty),+>)?)*)?),+>)? $(($($SuperStruct:ident $(::$($SuperPath:tt).+)? $($Mark:ident)?),+ $(,)?))?::$($Path:tt).+ $(($($Args:tt)*))?: $FieldType:tt $(, $($Opt:tt)+)?]}
+        $Variants:tt $Aliases:tt $Ranges:tt
+    ) => {
+        $crate::const_enum!{
+            ty_generics: [] [$($([$Param $($ParamName)?])+)?]
+            {$(#[$EnumAttr])* $Vis $EnumType}
+            $Struct {$($($Param $($ParamName)? $(: $Bound $($BoundName)? $(::$BoundPath)* $(<$($BoundArg),+>)? $(+ $More $($MoreName)? $(::$MorePath)* $(<$($MoreArg),+>)?)*)?),+)?}
+            [
+                [$($({$SuperStruct {$($($SuperPath).+)?} [$($Mark)?]})+)?]
+                ::{$($Path).+} [$(($($Args)*))?]
+            ]
+            $FieldType $Variants $Aliases $Ranges [$($($Opt)+)?]
+        }
+    };
+    (emit: {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$($Header:tt)*]} $($Rest:tt)*) => {
+        compile_error!(concat!(
+            "const_enum: cannot parse the header `[", stringify!($($Header)*), "]` of `", stringify!($EnumType),
+            "`, expected `Struct<..>::field: type, options..`, where generic parameters are lifetimes, ",
+            "`T`, `T: Bound + ..` (including `?Sized`) or `const N: type`"
+        ));
+    };
+    // 由泛型参数列表得到类型实参列表：`const N: usize` 只保留 `N`，每步处理一个参数。
+    (ty_generics: [$($Done:tt)*] [[const $Name:ident] $($Todo:tt)*] $($Rest:tt)*) => {
+        $crate::const_enum!{ ty_generics: [$($Done)* $Name,] [$($Todo)*] $($Rest)* }
+    };
+    (ty_generics: [$($Done:tt)*] [[$Param:tt] $($Todo:tt)*] $($Rest:tt)*) => {
+        $crate::const_enum!{ ty_generics: [$($Done)* $Param,] [$($Todo)*] $($Rest)* }
+    };
+    (ty_generics: [$($Done:tt)*] [] {$($Enum:tt)*} $Struct:ident $ImplGen:tt [$($Access:tt)*]
+        $FieldType:tt $Variants:tt $Aliases:tt $Ranges:tt [$($Opt:tt)*]
+    ) => {
+        $crate::const_enum!{
+            kind: $FieldType,
+            parse_options: {$($Enum)* [$Struct $ImplGen {$($Done)*} $($Access)*: $FieldType]}
+            $Variants $Aliases $Ranges [] [] [] [] []
+            $($Opt)*
+        }
+    };
+    // 由字段类型决定生成方式：整数类型使用 `#[repr]`，`char`、`bool` 与 `&'static str` 另行查表。
+    (kind: char, $Next:ident: {$($Head:tt)*} $($Rest:tt)*) => {
+        $crate::const_enum!{ $Next: {$($Head)* [plain]} $($Rest)* }
+    };
+    (kind: bool, $Next:ident: {$($Head:tt)*} $($Rest:tt)*) => {
+        $crate::const_enum!{ $Next: {$($Head)* [plain]} $($Rest)* }
+    };
+    (kind: $FieldType:ident, $Next:ident: {$($Head:tt)*} $($Rest:tt)*) => {
+        $crate::const_enum!{ $Next: {$($Head)* [repr]} $($Rest)* }
+    };
+    (kind: $FieldType:ty, $Next:ident: {$($Head:tt)*} $($Rest:tt)*) => {
+        $crate::const_enum!{ $Next: {$($Head)* [str]} $($Rest)* }
+    };
+    // 头部选项：`Low..=Upper`（可用 `|` 连接多个）、`mask = 表达式`、`shift = 表达式`、`field_only`，顺序不限。
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt [] $Mask:tt $Shift:tt $Flag:tt
+        $($Low:literal ..= $Upper:literal)|+ $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default [$($Low ..= $Upper),+] $Mask $Shift $Flag
+            $($($Rest)*)?
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt [] $Shift:tt $Flag:tt
+        mask = $Mask:expr $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default $Range [$Mask] $Shift $Flag
+            $($($Rest)*)?
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt $Mask:tt [] $Flag:tt
+        shift = $Shift:expr $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default $Range $Mask [$Shift] $Flag
+            $($($Rest)*)?
+        }
+    };
+    // 由 `find_default` 加上，记录带 `#[default]` 的变体。
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt [] $Range:tt $Mask:tt $Shift:tt $Flag:tt
+        @default $Default:ident $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges [$Default] $Range $Mask $Shift $Flag
+            $($($Rest)*)?
+        }
+    };
+    // `field_only`、`default`、`no_from` 互斥，决定生成哪些整个结构体的转换。
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt $Mask:tt $Shift:tt []
+        field_only $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default $Range $Mask $Shift [field_only]
+            $($($Rest)*)?
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt $Mask:tt $Shift:tt []
+        default $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default $Range $Mask $Shift [default]
+            $($($Rest)*)?
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt $Mask:tt $Shift:tt []
+        no_from $(, $($Rest:tt)*)?
+    ) => {
+        $crate::const_enum!{
+            parse_options: $Head $Variants $Aliases $Ranges $Default $Range $Mask $Shift [no_from]
+            $($($Rest)*)?
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt [] [] $Flag:tt) => {
+        $crate::const_enum!{
+            expand: $Head $Variants $Aliases $Ranges $Default $Range [] $Flag
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt [$Mask:expr] [] $Flag:tt) => {
+        $crate::const_enum!{
+            expand: $Head $Variants $Aliases $Ranges $Default $Range [$Mask, 0] $Flag
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt [$Mask:expr] [$Shift:expr] $Flag:tt) => {
+        $crate::const_enum!{
+            expand: $Head $Variants $Aliases $Ranges $Default $Range [$Mask, $Shift] $Flag
+        }
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt [] [$Shift:expr] $Flag:tt) => {
+        compile_error!("const_enum: `shift` requires `mask`");
+    };
+    (parse_options: $Head:tt $Variants:tt $Aliases:tt $Ranges:tt $Default:tt $Range:tt $Mask:tt $Shift:tt $Flag:tt $($Rest:tt)+) => {
+        compile_error!(concat!(
+            "const_enum: unexpected or repeated option `", stringify!($($Rest)+),
+            "`, expected `Low..=Upper [| ..]`, `mask = ..`, `shift = ..`, `field_only`, `default` or `no_from`"
+        ));
+    };
+    (expand:
+        {$(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident [$Struct:ident $ImplGen:tt $TyGen:tt [$($Targets:tt)*]::$Access:tt $Call:tt: $FieldType:tt] $Kind:tt}
+        [$({$(#[$Attr:meta])* $Variance:ident $Value:expr})+]
+        [$({$(#[$AliasAttr:meta])* $Alias:ident $Target:ident})*]
+        [$({$(#[$RangedAttr:meta])* $Ranged:ident ($Inner:ty) $Range:expr})*]
+        $Default:tt
+        [$($Low:literal ..= $Upper:literal),*]
+        $Bits:tt
+        $Flag:tt
+    ) => {
+        $crate::const_enum!{
+            def_enum: $Kind $(#[$EnumAttr])* $Vis $EnumType, $FieldType,
+            $($(#[$Attr])* $Variance $Value),+;
+            $($(#[$RangedAttr])* $Ranged ($Inner) $Range),*;
+            $($(#[$AliasAttr])* $Alias $Target),*
+        }
+        $crate::const_enum!{
+            default: $EnumType, $FieldType, $Default
+        }
+        $crate::const_enum!{
+            into_struct:
+            $EnumType, $Struct $ImplGen $TyGen::$Access $Call: $FieldType, $Flag
+        }
+        $(
+            $crate::const_enum!{
+                target: $EnumType, $FieldType, $Flag, $Access $Call, $Targets
             }
+        )*
+        $crate::const_enum!{
+            check:
+            $EnumType, $FieldType, [$($Low ..= $Upper),*], [$($Low),*], [$($Upper),*], ($($Low..=$Upper)|*),
+            $($Variance $Value),+;
+            $($Ranged $Range),*
+        }
+        $(
             $crate::const_enum!{
-                into_struct:
-                $EnumType, $Struct$(($SuperStruct))?::$Field:$FieldType,
-                $($Variance $Value),+
+                check_payload: $FieldType, $Ranged ($Inner)
+            }
+        )*
+        $crate::const_enum!{
+            check_unique: $Kind
+            $EnumType, $FieldType, [$($Value),+], [$($crate::const_enum!(range_bound: $FieldType, $Range, start)),*],
+            [$($crate::const_enum!(range_bound: $FieldType, $Range, end)),*],
+            $($Variance $Value),+;
+            $($Ranged $Range),*
+        }
+        $crate::const_enum!{
+            check_bits:
+            $EnumType, $FieldType, $Bits,
+            $($Variance $Value),+;
+            $($Ranged $Range),*
+        }
+        $crate::const_enum!{
+            raw_conv: $Kind
+            $EnumType, $FieldType, [$($Low ..= $Upper),*],
+            $($Variance $Value),+;
+            $($Ranged $Range),*
+        }
+        $crate::const_enum!{
+            field_bits:
+            $EnumType, $FieldType, $Bits
+        }
+        $crate::const_enum!{
+            variants_meta:
+            $EnumType, $FieldType,
+            $($Variance $Value),+;
+            $($Ranged),*
+        }
+        $crate::const_enum!{
+            fmt: $Kind
+            $EnumType, $FieldType,
+            $($Variance $Value),+;
+            $($Ranged),*;
+            $($Alias $Target),*
+        }
+        $crate::const_enum!{
+            as_enum:
+            $Vis $Struct $ImplGen $TyGen::$Access $Call, $EnumType, $FieldType, $Flag
+        }
+    };
+    (open_struct: {$Vis:vis $Open:ident, $EnumType:ident, $FieldType:tt $Kind:tt}
+        [$({$(#[$Attr:meta])* $Variance:ident $Value:expr})+]
+        [$({$(#[$AliasAttr:meta])* $Alias:ident $Target:ident})*]
+        $Ranges:tt
+    ) => {
+        #[doc = concat!("可保存任意原始值的 `", stringify!($EnumType), "`，已知值可用关联常量匹配。")]
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $Vis struct $Open(pub $FieldType);
+
+        #[allow(deprecated)]
+        impl $Open {
+            $(
+                #[doc = concat!("`", stringify!($EnumType), "::", stringify!($Variance), "`")]
+                #[allow(non_upper_case_globals)]
+                pub const $Variance: $Open = $Open($Value);
+            )+
+            $(
+                #[doc = concat!("`", stringify!($EnumType), "::", stringify!($Alias), "`")]
+                #[allow(non_upper_case_globals)]
+                pub const $Alias: $Open = $Open::$Target;
+            )*
+
+            /// 已知值返回对应的枚举，否则返回 `None`。
+            #[inline]
+            pub const fn known(self) -> core::option::Option<$EnumType> {
+                match $EnumType::from_field(self.0) {
+                    $crate::ConstEnum::Wellknown(v) => core::option::Option::Some(v),
+                    $crate::ConstEnum::Unknown(_) => core::option::Option::None,
+                }
+            }
+        }
+        impl core::convert::From<$FieldType> for $Open {
+            #[inline]
+            fn from(v: $FieldType) -> $Open {
+                $Open(v)
+            }
+        }
+        impl core::convert::From<$Open> for $FieldType {
+            #[inline]
+            fn from(v: $Open) -> $FieldType {
+                v.0
+            }
+        }
+        #[allow(deprecated)]
+        impl core::fmt::Display for $Open {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match $EnumType::from_field(self.0) {
+                    $crate::ConstEnum::Wellknown(v) => core::fmt::Display::fmt(&v, f),
+                    $crate::ConstEnum::Unknown(v) => write!(f, "Unknown({})", $crate::__Raw(&v)),
+                }
+            }
+        }
+    };
+    (def_enum: [repr] $(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident, $FieldType:tt,
+        $($(#[$Attr:meta])* $Variance:ident $Value:expr),+;
+        $($(#[$RangedAttr:meta])* $Ranged:ident ($Inner:ty) $Range:expr),*;
+        $($Aliases:tt)*
+    ) => {
+        #[repr($FieldType)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $(#[$EnumAttr])*
+        $Vis enum $EnumType {
+            $(
+                $(#[$Attr])*
+                $Variance = $Value,
+            )+
+            $(
+                $(#[$RangedAttr])*
+                $Ranged($FieldType) = $crate::const_enum!(range_bound: $FieldType, $Range, start),
+            )*
+        }
+        $crate::const_enum!{
+            def_aliases: $Vis $EnumType, $($Aliases)*
+        }
+    };
+    // 不能作为 `#[repr]` 的类型不设判别值，原始值由 `from_raw`、`to_raw` 查表转换。
+    (def_enum: [$Kind:ident] $(#[$EnumAttr:meta])* $Vis:vis $EnumType:ident, $FieldType:tt,
+        $($(#[$Attr:meta])* $Variance:ident $Value:expr),+;
+        $($(#[$RangedAttr:meta])* $Ranged:ident ($Inner:ty) $Range:expr),*;
+        $($Aliases:tt)*
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $(#[$EnumAttr])*
+        $Vis enum $EnumType {
+            $(
+                $(#[$Attr])*
+                $Variance,
+            )+
+            $(
+                $(#[$RangedAttr])*
+                $Ranged($FieldType),
+            )*
+        }
+        $crate::const_enum!{
+            def_aliases: $Vis $EnumType, $($Aliases)*
+        }
+    };
+    (def_aliases: $Vis:vis $EnumType:ident, $($(#[$AliasAttr:meta])* $Alias:ident $Target:ident),*) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            $(
+                $(#[$AliasAttr])*
+                #[allow(non_upper_case_globals)]
+                $Vis const $Alias: $EnumType = $EnumType::$Target;
+            )*
+        }
+    };
+    // `field_only`、`no_from` 不生成 `From<枚举>`；`default` 以 `Default::default()` 填充其余字段。
+    // 以方法读取的字段是只读的，不生成 `From<枚举>`。
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::$Access:tt $Call:tt: $FieldType:ty, [field_only]) => {};
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::$Access:tt $Call:tt: $FieldType:ty, [no_from]) => {};
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::$Access:tt [$Call:tt]: $FieldType:ty, [$($Flag:ident)?]) => {};
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} []: $FieldType:ty, [default]) => {
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> core::convert::From<$EnumType> for $Struct<$($TyGen)*>
+        where
+            Self: core::default::Default,
+        {
+            #[inline]
+            fn from(v: $EnumType) -> Self {
+                let mut s = <Self as core::default::Default>::default();
+                s.$($Path).+ = v.into_field(s.$($Path).+);
+                s
+            }
+        }
+    };
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$Field:tt} []: $FieldType:ty, []) => {
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> core::convert::From<$EnumType> for $Struct<$($TyGen)*> {
+            #[inline]
+            fn from(v: $EnumType) -> Self {
+                Self {
+                    $Field: v.into_field(<$FieldType as core::default::Default>::default())
+                }
+            }
+        }
+    };
+    (into_struct: $EnumType:ident, $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} []: $FieldType:ty, []) => {
+        compile_error!(concat!(
+            "const_enum: cannot build `", stringify!($Struct), "` from nested field `",
+            stringify!($($Path).+), "`, use `default` or `no_from`"
+        ));
+    };
+    // 额外的转换目标，未写字段时沿用主结构体的字段；带 `as_enum` 时同样为其实现 `AsEnum` 等。
+    (target: $EnumType:ident, $FieldType:ty, $Flag:tt, $Access:tt $Call:tt, {$Target:ident {} $Mark:tt}) => {
+        $crate::const_enum!{
+            target: $EnumType, $FieldType, $Flag, $Access $Call, {$Target $Access $Call $Mark}
+        }
+    };
+    (target: $EnumType:ident, $FieldType:ty, $Flag:tt, $Access:tt $Call:tt, {$Target:ident {$($Path:tt).+} $Mark:tt}) => {
+        $crate::const_enum!{
+            target: $EnumType, $FieldType, $Flag, $Access $Call, {$Target {$($Path).+} [] $Mark}
+        }
+    };
+    (target: $EnumType:ident, $FieldType:ty, $Flag:tt, $PrimaryAccess:tt $PrimaryCall:tt, {$Target:ident $Access:tt $Call:tt []}) => {
+        $crate::const_enum!{
+            into_struct: $EnumType, $Target {} {}::$Access $Call: $FieldType, $Flag
+        }
+    };
+    (target: $EnumType:ident, $FieldType:ty, $Flag:tt, $PrimaryAccess:tt $PrimaryCall:tt, {$Target:ident $Access:tt $Call:tt [as_enum]}) => {
+        $crate::const_enum!{
+            into_struct: $EnumType, $Target {} {}::$Access $Call: $FieldType, $Flag
+        }
+        $crate::const_enum!{
+            as_enum: $Target {} {}::$Access $Call, $EnumType, $FieldType, []
+        }
+    };
+    (target: $EnumType:ident, $FieldType:ty, $Flag:tt, $PrimaryAccess:tt $PrimaryCall:tt, {$Target:ident $Access:tt $Call:tt [$Mark:ident]}) => {
+        compile_error!(concat!("const_enum: unknown marker `", stringify!($Mark), "` on `", stringify!($Target), "`, expected `as_enum`"));
+    };
+    (check: $EnumType:ident, $FieldType:ty, [], $Lows:tt, $Uppers:tt, $Domain:tt,
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {};
+    (check: $EnumType:ident, $FieldType:ty, [$($Low:literal ..= $Upper:literal),+], $Lows:tt, $Uppers:tt, $Domain:tt,
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {
+        $(
+            #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+            const _: () = assert!(
+                ($Low as $FieldType) <= ($Upper as $FieldType),
+                concat!(
+                    "const_enum: `", stringify!($EnumType), "` has an empty range ",
+                    stringify!($Low), "..=", stringify!($Upper)
+                )
+            );
+        )+
+        $(
+            #[allow(deprecated)]
+            const _: () = assert!(
+                $EnumType::in_range($Value),
+                concat!(
+                    "const_enum: variant `", stringify!($EnumType), "::", stringify!($Variance),
+                    "` (", stringify!($Value), ") is outside the range ", stringify!$Domain
+                )
+            );
+        )+
+        $(
+            #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+            const _: () = {
+                let (lows, uppers): (&[$FieldType], &[$FieldType]) = (&$Lows, &$Uppers);
+                let range: core::ops::RangeInclusive<$FieldType> = $Range;
+                let (lo, hi) = (*range.start(), *range.end());
+                let mut inside = false;
+                let mut i = 0;
+                while i < lows.len() {
+                    if lows[i] <= lo && hi <= uppers[i] {
+                        inside = true;
+                    }
+                    i += 1;
+                }
+                assert!(
+                    inside,
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Ranged),
+                        "` (", stringify!($Range), ") is outside the range ",
+                        stringify!$Domain
+                    )
+                );
+            };
+        )*
+    };
+    // `&str` 不能在常量中比较，逐字节比较。
+    (check_unique: [str] $EnumType:ident, $FieldType:ty, $Values:tt, $Los:tt, $His:tt,
+        $($Variance:ident $Value:expr),+;
+    ) => {
+        $(
+            const _: () = {
+                let values: &[$FieldType] = &$Values;
+                let v: $FieldType = $Value;
+                let mut count = 0;
+                let mut i = 0;
+                while i < values.len() {
+                    if $crate::__str_eq(values[i], v) {
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                assert!(
+                    count == 1,
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Variance),
+                        "` (", stringify!($Value), ") shares its value with another variant; ",
+                        "use `Alias = Name` to declare an alias"
+                    )
+                );
+            };
+        )+
+    };
+    (check_unique: [$Kind:ident] $EnumType:ident, $FieldType:ty, $Values:tt, $Los:tt, $His:tt,
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {
+        $(
+            const _: () = {
+                let values: &[$FieldType] = &$Values;
+                let (los, his): (&[$FieldType], &[$FieldType]) = (&$Los, &$His);
+                let v: $FieldType = $Value;
+                let mut count = 0;
+                let mut i = 0;
+                while i < values.len() {
+                    if values[i] == v {
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                let mut i = 0;
+                while i < los.len() {
+                    if los[i] <= v && v <= his[i] {
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                assert!(
+                    count == 1,
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Variance),
+                        "` (", stringify!($Value), ") shares its value with another variant; ",
+                        "use `Alias = Name` to declare an alias"
+                    )
+                );
+            };
+        )+
+        $(
+            const _: () = {
+                let (los, his): (&[$FieldType], &[$FieldType]) = (&$Los, &$His);
+                let range: core::ops::RangeInclusive<$FieldType> = $Range;
+                let (lo, hi) = (*range.start(), *range.end());
+                assert!(
+                    lo <= hi,
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Ranged),
+                        "` has an empty range ", stringify!($Range)
+                    )
+                );
+                let mut count = 0;
+                let mut i = 0;
+                while i < los.len() {
+                    if los[i] <= hi && lo <= his[i] {
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                assert!(
+                    count == 1,
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Ranged),
+                        "` (", stringify!($Range), ") overlaps another ranged variant"
+                    )
+                );
+            };
+        )*
+    };
+    // 区间变体写出的负载类型须与字段类型相同，生成的枚举总以字段类型作负载。
+    (check_payload: $FieldType:ty, $Ranged:ident ($Inner:ty)) => {
+        const _: () = {
+            #[allow(dead_code)]
+            struct $Ranged;
+            const fn check<T: $crate::__SameType<F, V>, F, V>() {}
+            check::<$Inner, $FieldType, $Ranged>();
+        };
+    };
+    (check_bits: $EnumType:ident, $FieldType:ty, [], $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*) => {};
+    (check_bits: $EnumType:ident, $FieldType:ty, [$Mask:expr, $Shift:expr],
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {
+        const _: () = assert!(
+            $Shift < <$FieldType>::BITS,
+            concat!(
+                "const_enum: shift ", stringify!($Shift), " of `", stringify!($EnumType),
+                "` is not less than the bit width of `", stringify!($FieldType), "`"
+            )
+        );
+        // 位移越界时只报告上面的错误，不再逐个检查变体。
+        $(
+            const _: () = {
+                let mask: $FieldType = $Mask;
+                let shift: u32 = $Shift;
+                let v: $FieldType = $Value;
+                assert!(
+                    shift >= <$FieldType>::BITS
+                        || ((v << shift) >> shift == v && (v << shift) & !mask == 0),
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Variance),
+                        "` (", stringify!($Value), ") does not fit in mask ", stringify!($Mask),
+                        " shifted by ", stringify!($Shift)
+                    )
+                );
+            };
+        )+
+        $(
+            const _: () = {
+                let mask: $FieldType = $Mask;
+                let shift: u32 = $Shift;
+                let range: core::ops::RangeInclusive<$FieldType> = $Range;
+                let (lo, hi) = (*range.start(), *range.end());
+                assert!(
+                    shift >= <$FieldType>::BITS
+                        || ((lo << shift) >> shift == lo && (lo << shift) & !mask == 0
+                            && (hi << shift) >> shift == hi && (hi << shift) & !mask == 0),
+                    concat!(
+                        "const_enum: variant `", stringify!($EnumType), "::", stringify!($Ranged),
+                        "` (", stringify!($Range), ") does not fit in mask ",
+                        stringify!($Mask), " shifted by ", stringify!($Shift)
+                    )
+                );
+            };
+        )*
+    };
+    (field_bits: $EnumType:ident, $FieldType:ty, []) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 由结构体字段的值解码枚举，等同于 `from_raw`。
+            #[inline]
+            pub const fn from_field(field: $FieldType) -> $crate::ConstEnum<Self, $FieldType> {
+                Self::from_raw(field)
+            }
+
+            /// 将枚举写入结构体字段的值，返回写入后的字段值。
+            #[inline]
+            pub const fn into_field(self, _field: $FieldType) -> $FieldType {
+                self.to_raw()
+            }
+        }
+    };
+    (field_bits: $EnumType:ident, $FieldType:ty, [$Mask:expr, $Shift:expr]) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 字段中属于本枚举的位。
+            pub const MASK: $FieldType = $Mask;
+            /// 本枚举在字段中的位移。
+            pub const SHIFT: u32 = $Shift;
+
+            /// 取出字段中 `MASK` 对应的位并解码。
+            #[inline]
+            pub const fn from_field(field: $FieldType) -> $crate::ConstEnum<Self, $FieldType> {
+                Self::from_raw((field & Self::MASK) >> Self::SHIFT)
+            }
+
+            /// 将枚举写入字段中 `MASK` 对应的位，其余位保持不变，返回写入后的字段值。
+            #[inline]
+            pub const fn into_field(self, field: $FieldType) -> $FieldType {
+                (field & !Self::MASK) | ((self.to_raw() << Self::SHIFT) & Self::MASK)
+            }
+        }
+    };
+    (raw_conv: $Kind:tt $EnumType:ident, $FieldType:ty, [$($Low:literal ..= $Upper:literal),*],
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {
+        $crate::const_enum!{
+            in_range: $EnumType, $FieldType, [$($Low ..= $Upper),*]
+        }
+        $crate::const_enum!{
+            from_raw: $Kind $EnumType, $FieldType, $($Variance $Value),+; $($Ranged $Range),*
+        }
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 与 `from_raw` 相同，但将未知的原始值再分为范围内的保留值与范围外的值。
+            #[inline]
+            pub const fn classify(v: $FieldType) -> $crate::Classified<Self, $FieldType> {
+                match Self::from_raw(v) {
+                    $crate::ConstEnum::Wellknown(e) => $crate::Classified::Wellknown(e),
+                    $crate::ConstEnum::Unknown(v) if Self::in_range(v) => $crate::Classified::Reserved(v),
+                    $crate::ConstEnum::Unknown(v) => $crate::Classified::OutOfRange(v),
+                }
+            }
+        }
+        $crate::const_enum!{
+            to_raw: $Kind $EnumType, $FieldType, $($Variance $Value),+; $($Ranged),*
+        }
+        #[allow(deprecated)]
+        impl core::convert::TryFrom<$FieldType> for $EnumType {
+            type Error = $FieldType;
+            #[inline]
+            fn try_from(v: $FieldType) -> core::result::Result<Self, $FieldType> {
+                $EnumType::from_raw(v).into()
+            }
+        }
+        #[allow(deprecated)]
+        impl core::convert::From<$EnumType> for $FieldType {
+            #[inline]
+            fn from(v: $EnumType) -> $FieldType {
+                v.to_raw()
+            }
+        }
+    };
+    (default: $EnumType:ident, $FieldType:ty, []) => {};
+    // `Default` 由枚举上的 `#[derive(Default)]` 实现。
+    (default: $EnumType:ident, $FieldType:ty, [$Default:ident]) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 与 `from_raw` 相同，但未知的原始值解码为带 `#[default]` 的变体。
+            #[inline]
+            pub const fn from_raw_or_default(v: $FieldType) -> Self {
+                match Self::from_raw(v) {
+                    $crate::ConstEnum::Wellknown(v) => v,
+                    $crate::ConstEnum::Unknown(_) => $EnumType::$Default,
+                }
             }
-            $crate::const_enum!{
-                as_enum:
-                $Vis $Struct::$Field, $EnumType, $FieldType, $($Low ..= $Upper,)?
-                $($Variance $Value),+
+        }
+    };
+    (in_range: $EnumType:ident, $FieldType:ty, []) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 原始值是否落在头部给出的常量范围内；未给出范围时总为 `true`。
+            #[inline]
+            pub const fn in_range(v: $FieldType) -> bool {
+                let _ = v;
+                true
             }
-        )+
+        }
     };
-    (def_enum: $Vis:vis $EnumType:ident, $FieldType:tt, $($(#[$Doc:meta])? $Variance:ident $Value:literal),+) => {
-        #[repr($FieldType)]
-        #[derive(Debug)]
-        $Vis enum $EnumType {
-            $(
-                $(#[$Doc])?
-                $Variance = $Value
-            ),+
+    (in_range: $EnumType:ident, $FieldType:ty, [$($Low:literal ..= $Upper:literal),+]) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 原始值是否落在头部给出的常量范围内。
+            #[inline]
+            pub const fn in_range(v: $FieldType) -> bool {
+                matches!(v, $($Low..=$Upper)|+)
+            }
         }
     };
-    (into_struct: $EnumType:ident,$Struct:ident $(($SuperStruct:ident))?::$Field:ident:$FieldType:ty, $($Variance:ident $Value:literal),+) => {
-        impl core::convert::Into<$Struct> for $EnumType {
+    (from_raw: [str] $EnumType:ident, $FieldType:ty, $($Variance:ident $Value:expr),+;) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 将原始值解码为枚举，可在 `const` 上下文中使用。
             #[inline]
-            fn into(self) -> $Struct {
-                    $Struct {
-                        $Field: self as $FieldType
+            pub const fn from_raw(v: $FieldType) -> $crate::ConstEnum<Self, $FieldType> {
+                $(
+                    if $crate::__str_eq(v, $Value) {
+                        return $crate::ConstEnum::Wellknown($EnumType::$Variance);
                     }
+                )+
+                $crate::ConstEnum::Unknown(v)
             }
         }
-        $(
-            impl core::convert::Into<$SuperStruct> for $EnumType {
-                #[inline]
-                fn into(self) -> $SuperStruct {
-                    $SuperStruct {
-                        $Field: self as $FieldType
+    };
+    (from_raw: [$Kind:ident] $EnumType:ident, $FieldType:ty,
+        $($Variance:ident $Value:expr),+; $($Ranged:ident $Range:expr),*
+    ) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 将原始值解码为枚举，可在 `const` 上下文中使用。
+            #[inline]
+            #[allow(unused_comparisons, clippy::absurd_extreme_comparisons, clippy::manual_range_contains)]
+            #[allow(non_upper_case_globals)]
+            pub const fn from_raw(v: $FieldType) -> $crate::ConstEnum<Self, $FieldType> {
+                if !Self::in_range(v) {
+                    return $crate::ConstEnum::Unknown(v);
+                }
+                $(
+                    if $crate::const_enum!(range_bound: $FieldType, $Range, start) <= v
+                        && v <= $crate::const_enum!(range_bound: $FieldType, $Range, end) {
+                        return $crate::ConstEnum::Wellknown($EnumType::$Ranged(v));
+                    }
+                )*
+                // 借助同名常量作为匹配模式；常量取自 `to_raw`，不在此处对变体的值求值，
+                // 以免值表达式中与变体同名的外部常量被这些局部常量遮蔽。
+                {
+                    $(
+                        const $Variance: $FieldType = $EnumType::$Variance.to_raw();
+                    )+
+                    match v {
+                        $(
+                            $Variance => $crate::ConstEnum::Wellknown($EnumType::$Variance),
+                        )+
+                        _ => $crate::ConstEnum::Unknown(v)
                     }
                 }
             }
-        )?
+        }
     };
-    (as_enum: $Vis:vis $Struct:ident::$Field:ident, $EnumType:ident, $FieldType:ty, $($Low:literal ..= $Upper:literal,)? $($Variance:ident $Value:literal),+ ) => {
-        impl $crate::AsEnum for $Struct {
-            type TargetEnum = $EnumType;
-            type BaseType = $FieldType;
+    (to_raw: [repr] $EnumType:ident, $FieldType:ty, $($Variance:ident $Value:expr),+;) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 取得枚举对应的原始值。
             #[inline]
-            fn as_enum(&self) -> $crate::ConstEnum<$EnumType, $FieldType> {
+            pub const fn to_raw(self) -> $FieldType {
+                self as $FieldType
+            }
+        }
+    };
+    // 含区间变体或不带判别值时不能直接 `as` 转换。
+    (to_raw: $Kind:tt $EnumType:ident, $FieldType:ty, $($Variance:ident $Value:expr),+; $($Ranged:ident),*) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 取得枚举对应的原始值。
+            #[inline]
+            pub const fn to_raw(self) -> $FieldType {
+                match self {
+                    $(
+                        $EnumType::$Variance => $Value,
+                    )+
+                    $(
+                        $EnumType::$Ranged(v) => v,
+                    )*
+                }
+            }
+        }
+    };
+    (variants_meta: $EnumType:ident, $FieldType:ty, $($Variance:ident $Value:expr),+; $($Ranged:ident),*) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 按声明顺序排列的全部变体（不含别名与区间变体）。
+            pub const VARIANTS: &'static [Self] = &[$($EnumType::$Variance),+];
+            /// `VARIANTS` 中的变体个数。
+            pub const COUNT: usize = Self::VARIANTS.len();
+            /// 与 `VARIANTS` 一一对应的原始值。
+            pub const VALUES: &'static [$FieldType] = &[$($Value),+];
+
+            /// 变体名称。
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        $EnumType::$Variance => stringify!($Variance),
+                    )+
+                    $(
+                        $EnumType::$Ranged(_) => stringify!($Ranged),
+                    )*
+                }
+            }
+
+            /// 按声明顺序遍历 `VARIANTS` 中的变体。
+            #[inline]
+            pub fn iter() -> core::iter::Copied<core::slice::Iter<'static, Self>> {
+                Self::VARIANTS.iter().copied()
+            }
+        }
+    };
+    (fmt: $Kind:tt $EnumType:ident, $FieldType:ty, $($Variance:ident $Value:expr),+; $($Ranged:ident),*; $($Alias:ident $Target:ident),*) => {
+        #[allow(deprecated)]
+        impl core::fmt::Display for $EnumType {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $(
+                    if let $EnumType::$Ranged(v) = self {
+                        return write!(f, concat!(stringify!($Ranged), "({})"), $crate::__Raw(&v));
+                    }
+                )*
+                f.write_str(self.name())
+            }
+        }
+        #[allow(deprecated)]
+        impl $EnumType {
+            /// 与 `FromStr` 相同，但按名称匹配时忽略 ASCII 大小写。
+            pub fn from_str_ignore_case(s: &str) -> core::result::Result<Self, $crate::ParseEnumError> {
                 $(
-                    if !($Low..=$Upper).contains(&self.$Field) {
-                        return $crate::ConstEnum::Unknown(self.$Field);
+                    if s.eq_ignore_ascii_case(stringify!($Variance)) {
+                        return core::result::Result::Ok($EnumType::$Variance);
                     }
-                )?
-                match self.$Field {
+                )+
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($Alias)) {
+                        return core::result::Result::Ok($EnumType::$Target);
+                    }
+                )*
+                Self::from_str_raw(s)
+            }
+
+        }
+        $crate::const_enum!{
+            parse_raw: $Kind $EnumType, $FieldType
+        }
+        #[allow(deprecated)]
+        impl core::str::FromStr for $EnumType {
+            type Err = $crate::ParseEnumError;
+            /// 按变体（或别名）名称解析，或按十进制、`0x` 前缀的十六进制原始值解析。
+            fn from_str(s: &str) -> core::result::Result<Self, $crate::ParseEnumError> {
+                match s {
                     $(
-                        $Value => $crate::ConstEnum::Wellknown($EnumType::$Variance),
+                        stringify!($Variance) => core::result::Result::Ok($EnumType::$Variance),
                     )+
-                    _ => $crate::ConstEnum::Unknown(self.$Field)
+                    $(
+                        stringify!($Alias) => core::result::Result::Ok($EnumType::$Target),
+                    )*
+                    _ => Self::from_str_raw(s),
+                }
+            }
+        }
+    };
+    // 区间变体的上下界，先约束为 `RangeInclusive` 以得到清楚的类型错误。
+    (range_bound: $FieldType:ty, $Range:expr, $Bound:ident) => {
+        *{
+            let range: core::ops::RangeInclusive<$FieldType> = $Range;
+            range
+        }.$Bound()
+    };
+    (parse_raw: [repr] $EnumType:ident, $FieldType:ty) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            fn from_str_raw(s: &str) -> core::result::Result<Self, $crate::ParseEnumError> {
+                let raw = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                    core::option::Option::Some(hex) => <$FieldType>::from_str_radix(hex, 16),
+                    core::option::Option::None => s.parse::<$FieldType>(),
+                };
+                match raw {
+                    core::result::Result::Ok(v) => Self::from_raw(v).ok_or($crate::ParseEnumError::Unknown),
+                    core::result::Result::Err(_) => core::result::Result::Err($crate::ParseEnumError::Invalid),
+                }
+            }
+        }
+    };
+    (parse_raw: [plain] $EnumType:ident, $FieldType:ty) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            fn from_str_raw(s: &str) -> core::result::Result<Self, $crate::ParseEnumError> {
+                match s.parse::<$FieldType>() {
+                    core::result::Result::Ok(v) => Self::from_raw(v).ok_or($crate::ParseEnumError::Unknown),
+                    core::result::Result::Err(_) => core::result::Result::Err($crate::ParseEnumError::Invalid),
                 }
             }
         }
     };
+    // 字符串的原始值只能与已知值比较，不存在“非法”的原始值。
+    (parse_raw: [str] $EnumType:ident, $FieldType:ty) => {
+        #[allow(deprecated)]
+        impl $EnumType {
+            fn from_str_raw(s: &str) -> core::result::Result<Self, $crate::ParseEnumError> {
+                match Self::VALUES.iter().position(|v| *v == s) {
+                    core::option::Option::Some(i) => core::result::Result::Ok(Self::VARIANTS[i]),
+                    core::option::Option::None => core::result::Result::Err($crate::ParseEnumError::Unknown),
+                }
+            }
+        }
+    };
+    (as_enum: $Vis:vis $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} [$($Call:tt)?], $EnumType:ident, $FieldType:ty, [field_only]) => {
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> $crate::AsEnumField<$EnumType> for $Struct<$($TyGen)*> {
+            type BaseType = $FieldType;
+            #[inline]
+            fn as_enum_field(&self) -> $crate::ConstEnum<$EnumType, $FieldType> {
+                $EnumType::from_field(self.$($Path).+ $($Call)?)
+            }
+        }
+        $crate::const_enum!{
+            set_enum: $Struct {$($ImplGen)*} {$($TyGen)*}::{$($Path).+} [$($Call)?], $EnumType, [field_only]
+        }
+    };
+    (as_enum: $Vis:vis $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} [$($Call:tt)?], $EnumType:ident, $FieldType:ty, [$($Flag:ident)?]) => {
+        $crate::const_enum!{
+            as_enum: $Vis $Struct {$($ImplGen)*} {$($TyGen)*}::{$($Path).+} [$($Call)?], $EnumType, $FieldType, [field_only]
+        }
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> $crate::AsEnum for $Struct<$($TyGen)*> {
+            type TargetEnum = $EnumType;
+            type BaseType = $FieldType;
+            #[inline]
+            fn as_enum(&self) -> $crate::ConstEnum<$EnumType, $FieldType> {
+                $EnumType::from_field(self.$($Path).+ $($Call)?)
+            }
+        }
+        $crate::const_enum!{
+            set_enum: $Struct {$($ImplGen)*} {$($TyGen)*}::{$($Path).+} [$($Call)?], $EnumType, []
+        }
+    };
+    // 以方法读取的字段是只读的，不生成 setter。
+    (set_enum: $Struct:ident $ImplGen:tt $TyGen:tt::$Access:tt [$Call:tt], $EnumType:ident, $Flag:tt) => {};
+    (set_enum: $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} [], $EnumType:ident, [field_only]) => {
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> $crate::SetEnumField<$EnumType> for $Struct<$($TyGen)*> {
+            #[inline]
+            fn set_enum_field(&mut self, v: $EnumType) {
+                self.$($Path).+ = v.into_field(self.$($Path).+);
+            }
+        }
+    };
+    (set_enum: $Struct:ident {$($ImplGen:tt)*} {$($TyGen:tt)*}::{$($Path:tt).+} [], $EnumType:ident, []) => {
+        #[allow(deprecated)]
+        impl<$($ImplGen)*> $crate::SetEnum for $Struct<$($TyGen)*> {
+            #[inline]
+            fn set_enum(&mut self, v: $EnumType) {
+                self.$($Path).+ = v.into_field(self.$($Path).+);
+            }
+        }
+    };
 }
 
+/// 已知的枚举值或未知的原始值。
+///
+/// 组合子示例：
+///
+/// ```
+/// use const_enum::{ConstEnum, Wellknown, Unknown};
+///
+/// let known: ConstEnum<char, u8> = Wellknown('a');
+/// let unknown: ConstEnum<char, u8> = Unknown(7);
+///
+/// assert!(known.is_wellknown() && !known.is_unknown());
+/// assert!(unknown.is_unknown() && !unknown.is_wellknown());
+///
+/// assert_eq!(known.wellknown(), Some('a'));
+/// assert_eq!(unknown.wellknown(), None);
+/// assert_eq!(known.unknown(), None);
+/// assert_eq!(unknown.unknown(), Some(7));
+///
+/// assert_eq!(known.as_ref(), Wellknown(&'a'));
+/// assert_eq!(unknown.as_ref(), Unknown(&7));
+/// let (mut k, mut u) = (known, unknown);
+/// if let Wellknown(v) = k.as_mut() {
+///     *v = 'b';
+/// }
+/// if let Unknown(v) = u.as_mut() {
+///     *v += 1;
+/// }
+/// assert_eq!((k, u), (Wellknown('b'), Unknown(8)));
+///
+/// assert_eq!(known.map(|c| c as u32), Wellknown(97));
+/// assert_eq!(unknown.map(|c| c as u32), Unknown(7));
+/// assert_eq!(known.map_unknown(u32::from), Wellknown('a'));
+/// assert_eq!(unknown.map_unknown(u32::from), Unknown(7u32));
+/// assert_eq!(known.and_then(|c| Wellknown(c as u32)), Wellknown(97));
+/// assert_eq!(known.and_then(|_| Unknown::<u32, u8>(0)), Unknown(0));
+/// assert_eq!(unknown.and_then(|c| Wellknown(c as u32)), Unknown(7));
+///
+/// assert_eq!(known.unwrap_or('?'), 'a');
+/// assert_eq!(unknown.unwrap_or('?'), '?');
+/// assert_eq!(known.unwrap_or_else(char::from), 'a');
+/// assert_eq!(unknown.unwrap_or_else(char::from), '\u{7}');
+/// assert_eq!(known.unwrap_or_default(), 'a');
+/// assert_eq!(unknown.unwrap_or_default(), '\0');
+/// assert_eq!(known.ok_or("unknown"), Ok('a'));
+/// assert_eq!(unknown.ok_or("unknown"), Err("unknown"));
+/// assert_eq!(known.unwrap(), 'a');
+/// assert_eq!(known.expect("known"), 'a');
+///
+/// assert_eq!(Result::from(known), Ok('a'));
+/// assert_eq!(Result::from(unknown), Err(7));
+/// ```
+///
+/// 未知值上的 `unwrap`、`expect` 会 panic：
+///
+/// ```should_panic
+/// use const_enum::{ConstEnum, Unknown};
+///
+/// let unknown: ConstEnum<char, u8> = Unknown(7);
+/// unknown.unwrap();
+/// ```
+///
+/// ```should_panic
+/// use const_enum::{ConstEnum, Unknown};
+///
+/// let unknown: ConstEnum<char, u8> = Unknown(7);
+/// unknown.expect("unknown");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConstEnum<TargetEnum, BaseType> {
     Wellknown(TargetEnum),
     Unknown(BaseType),
 }
 
-impl<TragetEnum, BaseType: core::fmt::Debug> ConstEnum<TragetEnum, BaseType> {
-    pub fn unwrap(self) -> TragetEnum {
+impl<TargetEnum, BaseType> ConstEnum<TargetEnum, BaseType> {
+    /// 是否为已知的枚举值。
+    #[inline]
+    pub fn is_wellknown(&self) -> bool {
+        matches!(self, ConstEnum::Wellknown(_))
+    }
+
+    /// 是否为未知的原始值。
+    #[inline]
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ConstEnum::Unknown(_))
+    }
+
+    /// 转换为 `Option<TargetEnum>`，丢弃未知的原始值。
+    #[inline]
+    pub fn wellknown(self) -> Option<TargetEnum> {
+        match self {
+            ConstEnum::Wellknown(v) => Some(v),
+            ConstEnum::Unknown(_) => None,
+        }
+    }
+
+    /// 转换为 `Option<BaseType>`，丢弃已知的枚举值。
+    #[inline]
+    pub fn unknown(self) -> Option<BaseType> {
+        match self {
+            ConstEnum::Wellknown(_) => None,
+            ConstEnum::Unknown(v) => Some(v),
+        }
+    }
+
+    /// 由 `&ConstEnum` 转换为 `ConstEnum<&TargetEnum, &BaseType>`。
+    #[inline]
+    pub fn as_ref(&self) -> ConstEnum<&TargetEnum, &BaseType> {
+        match self {
+            ConstEnum::Wellknown(v) => ConstEnum::Wellknown(v),
+            ConstEnum::Unknown(v) => ConstEnum::Unknown(v),
+        }
+    }
+
+    /// 由 `&mut ConstEnum` 转换为 `ConstEnum<&mut TargetEnum, &mut BaseType>`。
+    #[inline]
+    pub fn as_mut(&mut self) -> ConstEnum<&mut TargetEnum, &mut BaseType> {
+        match self {
+            ConstEnum::Wellknown(v) => ConstEnum::Wellknown(v),
+            ConstEnum::Unknown(v) => ConstEnum::Unknown(v),
+        }
+    }
+
+    /// 对已知的枚举值应用 `f`，未知的原始值保持不变。
+    #[inline]
+    pub fn map<U, F: FnOnce(TargetEnum) -> U>(self, f: F) -> ConstEnum<U, BaseType> {
+        match self {
+            ConstEnum::Wellknown(v) => ConstEnum::Wellknown(f(v)),
+            ConstEnum::Unknown(v) => ConstEnum::Unknown(v),
+        }
+    }
+
+    /// 对未知的原始值应用 `f`，已知的枚举值保持不变。
+    #[inline]
+    pub fn map_unknown<U, F: FnOnce(BaseType) -> U>(self, f: F) -> ConstEnum<TargetEnum, U> {
+        match self {
+            ConstEnum::Wellknown(v) => ConstEnum::Wellknown(v),
+            ConstEnum::Unknown(v) => ConstEnum::Unknown(f(v)),
+        }
+    }
+
+    /// 对已知的枚举值应用返回 `ConstEnum` 的 `f`，未知的原始值保持不变。
+    #[inline]
+    pub fn and_then<U, F: FnOnce(TargetEnum) -> ConstEnum<U, BaseType>>(
+        self,
+        f: F,
+    ) -> ConstEnum<U, BaseType> {
+        match self {
+            ConstEnum::Wellknown(v) => f(v),
+            ConstEnum::Unknown(v) => ConstEnum::Unknown(v),
+        }
+    }
+
+    /// 取出已知的枚举值，未知时返回 `default`。
+    #[inline]
+    pub fn unwrap_or(self, default: TargetEnum) -> TargetEnum {
+        match self {
+            ConstEnum::Wellknown(v) => v,
+            ConstEnum::Unknown(_) => default,
+        }
+    }
+
+    /// 未知时以原始值调用 `f` 计算出枚举值。
+    #[inline]
+    pub fn unwrap_or_else<F: FnOnce(BaseType) -> TargetEnum>(self, f: F) -> TargetEnum {
+        match self {
+            ConstEnum::Wellknown(v) => v,
+            ConstEnum::Unknown(v) => f(v),
+        }
+    }
+
+    /// 取出已知的枚举值，未知时返回 `TargetEnum::default()`。
+    #[inline]
+    pub fn unwrap_or_default(self) -> TargetEnum
+    where
+        TargetEnum: Default,
+    {
+        match self {
+            ConstEnum::Wellknown(v) => v,
+            ConstEnum::Unknown(_) => TargetEnum::default(),
+        }
+    }
+
+    /// 转换为 `Result`，未知时以 `err` 作为错误。
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<TargetEnum, E> {
+        match self {
+            ConstEnum::Wellknown(v) => Ok(v),
+            ConstEnum::Unknown(_) => Err(err),
+        }
+    }
+}
+
+impl<TargetEnum, BaseType: Copy> ConstEnum<TargetEnum, BaseType> {
+    /// 以 `in_range`（通常为生成的 `枚举::in_range`）将未知的原始值分为保留值与范围外的值。
+    #[inline]
+    pub fn classify<F: FnOnce(BaseType) -> bool>(
+        self,
+        in_range: F,
+    ) -> Classified<TargetEnum, BaseType> {
+        match self {
+            ConstEnum::Wellknown(v) => Classified::Wellknown(v),
+            ConstEnum::Unknown(v) if in_range(v) => Classified::Reserved(v),
+            ConstEnum::Unknown(v) => Classified::OutOfRange(v),
+        }
+    }
+}
+
+impl<TargetEnum, BaseType: core::fmt::Debug> ConstEnum<TargetEnum, BaseType> {
+    /// 取出已知的枚举值，未知时 panic。
+    #[inline]
+    pub fn unwrap(self) -> TargetEnum {
         match self {
             ConstEnum::Wellknown(v) => v,
             ConstEnum::Unknown(v) => panic!("Unknown value {:?}", v),
         }
     }
+
+    /// 取出已知的枚举值，未知时以 `msg` panic。
+    #[inline]
+    pub fn expect(self, msg: &str) -> TargetEnum {
+        match self {
+            ConstEnum::Wellknown(v) => v,
+            ConstEnum::Unknown(v) => panic!("{}: {:?}", msg, v),
+        }
+    }
+}
+
+impl<TargetEnum, BaseType> From<ConstEnum<TargetEnum, BaseType>> for Result<TargetEnum, BaseType> {
+    #[inline]
+    fn from(v: ConstEnum<TargetEnum, BaseType>) -> Self {
+        match v {
+            ConstEnum::Wellknown(v) => Ok(v),
+            ConstEnum::Unknown(v) => Err(v),
+        }
+    }
+}
+
+impl<TargetEnum: core::fmt::Display, BaseType: DisplayRaw> core::fmt::Display
+    for ConstEnum<TargetEnum, BaseType>
+{
+    /// 已知值显示为枚举本身，未知值按 `DisplayRaw` 显示，如 `Unknown(0x21)`。
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConstEnum::Wellknown(v) => v.fmt(f),
+            ConstEnum::Unknown(v) => write!(f, "Unknown({})", __Raw(v)),
+        }
+    }
+}
+
+/// `ConstEnum` 的细分：未知的原始值按是否落在头部的常量范围内分为 `Reserved` 与 `OutOfRange`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Classified<TargetEnum, BaseType> {
+    Wellknown(TargetEnum),
+    /// 在范围内，但未分配给任何变体。
+    Reserved(BaseType),
+    /// 不在范围内。
+    OutOfRange(BaseType),
+}
+
+impl<TargetEnum, BaseType> Classified<TargetEnum, BaseType> {
+    /// 是否为已知的枚举值。
+    #[inline]
+    pub fn is_wellknown(&self) -> bool {
+        matches!(self, Classified::Wellknown(_))
+    }
+
+    /// 是否为范围内未分配的保留值。
+    #[inline]
+    pub fn is_reserved(&self) -> bool {
+        matches!(self, Classified::Reserved(_))
+    }
+
+    /// 是否为范围外的值。
+    #[inline]
+    pub fn is_out_of_range(&self) -> bool {
+        matches!(self, Classified::OutOfRange(_))
+    }
+
+    /// 转换为 `Option<TargetEnum>`，丢弃未知的原始值。
+    #[inline]
+    pub fn wellknown(self) -> Option<TargetEnum> {
+        match self {
+            Classified::Wellknown(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<TargetEnum, BaseType> From<Classified<TargetEnum, BaseType>>
+    for ConstEnum<TargetEnum, BaseType>
+{
+    /// 不再区分保留值与范围外的值。
+    #[inline]
+    fn from(v: Classified<TargetEnum, BaseType>) -> Self {
+        match v {
+            Classified::Wellknown(v) => ConstEnum::Wellknown(v),
+            Classified::Reserved(v) | Classified::OutOfRange(v) => ConstEnum::Unknown(v),
+        }
+    }
+}
+
+impl<TargetEnum: core::fmt::Display, BaseType: DisplayRaw> core::fmt::Display
+    for Classified<TargetEnum, BaseType>
+{
+    /// 已知值显示为枚举本身，其余显示为 `Reserved(0x3)`、`OutOfRange(0x21)`。
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Classified::Wellknown(v) => v.fmt(f),
+            Classified::Reserved(v) => write!(f, "Reserved({})", __Raw(v)),
+            Classified::OutOfRange(v) => write!(f, "OutOfRange({})", __Raw(v)),
+        }
+    }
+}
+
+/// 由字符串解析 `const_enum!` 生成的枚举时的错误。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseEnumError {
+    /// 既不是变体名称，也不是合法的数字。
+    Invalid,
+    /// 是合法的数字，但不对应任何已知变体。
+    Unknown,
+}
+
+impl core::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseEnumError::Invalid => f.write_str("invalid variant name or number"),
+            ParseEnumError::Unknown => f.write_str("unknown enum value"),
+        }
+    }
+}
+
+/// 原始值在 `Display` 中的写法：无符号整数为 `0x` 开头的十六进制，有符号整数为十进制，
+/// `char`、`bool` 与 `&str` 同 `Debug`。整数的写法都能由 `FromStr` 解析回来。
+pub trait DisplayRaw {
+    fn fmt_raw(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+macro_rules! display_raw {
+    ($Fmt:literal: $($Type:ty),+) => {
+        $(
+            impl DisplayRaw for $Type {
+                #[inline]
+                fn fmt_raw(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, $Fmt, self)
+                }
+            }
+        )+
+    };
+}
+
+display_raw!("{:#x}": u8, u16, u32, u64, u128, usize);
+display_raw!("{}": i8, i16, i32, i64, i128, isize);
+display_raw!("{:?}": char, bool, str);
+
+impl<T: DisplayRaw + ?Sized> DisplayRaw for &T {
+    #[inline]
+    fn fmt_raw(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        (**self).fmt_raw(f)
+    }
+}
+
+/// 供 `const_enum!` 以 `DisplayRaw` 的写法格式化原始值。
+#[doc(hidden)]
+pub struct __Raw<'a, T: ?Sized>(pub &'a T);
+
+impl<T: DisplayRaw + ?Sized> core::fmt::Display for __Raw<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt_raw(f)
+    }
 }
 
 pub trait AsEnum {
     type TargetEnum;
     type BaseType: Copy;
     fn as_enum(&self) -> ConstEnum<Self::TargetEnum, Self::BaseType>;
+
+    /// 未知的原始值解码为枚举的默认值（带 `#[default]` 的变体）。
+    #[inline]
+    fn as_enum_or_default(&self) -> Self::TargetEnum
+    where
+        Self::TargetEnum: Default,
+    {
+        self.as_enum().unwrap_or_default()
+    }
+}
+
+/// `AsEnum` 的逆操作：将枚举写回已有结构体的字段，结构体的其余字段保持不变。
+pub trait SetEnum: AsEnum {
+    fn set_enum(&mut self, v: Self::TargetEnum);
+}
+
+/// 以目标枚举区分的 `AsEnum`，同一结构体可为多个字段分别实现。
+pub trait AsEnumField<TargetEnum> {
+    type BaseType: Copy;
+    fn as_enum_field(&self) -> ConstEnum<TargetEnum, Self::BaseType>;
+
+    /// 未知的原始值解码为枚举的默认值（带 `#[default]` 的变体）。
+    #[inline]
+    fn as_enum_field_or_default(&self) -> TargetEnum
+    where
+        TargetEnum: Default,
+    {
+        self.as_enum_field().unwrap_or_default()
+    }
+}
+
+/// `AsEnumField` 的逆操作：将枚举写回对应字段，字段中的其余位保持不变。
+pub trait SetEnumField<TargetEnum> {
+    fn set_enum_field(&mut self, v: TargetEnum);
 }
 
 pub struct Hello {
@@ -155,5 +2309,32 @@ const_enum! {
     }
 }
 
+/// 供 `const_enum!` 检查区间变体的负载类型与字段类型相同。
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "const_enum: ranged variant `{Variant}` has payload type `{Self}`, expected the field type `{Field}`",
+    label = "expected `{Field}`"
+)]
+pub trait __SameType<Field, Variant> {}
+
+impl<T, V> __SameType<T, V> for T {}
+
+/// 供 `const_enum!` 在常量中比较 `&str`。
+#[doc(hidden)]
+pub const fn __str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 pub use self::ConstEnum::Unknown;
 pub use self::ConstEnum::Wellknown;
@@ -0,0 +1,444 @@
+//! 变体较多时展开步数不随变体个数增长，不受宏递归深度的限制；别名、区间变体可以与普通变体任意混排。
+
+use const_enum::{const_enum, AsEnum, Wellknown};
+
+pub struct Wide {
+    pub code: u8,
+}
+
+const_enum! {
+    pub Code [Wide::code: u8] {
+        #[default]
+        V0: 0,
+        #[deprecated]
+        V1: 1,
+        V2: 2,
+        V3: 3,
+        V4: 4,
+        V5: 5,
+        V6: 6,
+        V7: 7,
+        V8: 8,
+        V9: 9,
+        V10: 10,
+        V11: 11,
+        V12: 12,
+        V13: 13,
+        V14: 14,
+        V15: 15,
+        V16: 16,
+        V17: 17,
+        V18: 18,
+        V19: 19,
+        V20: 20,
+        V21: 21,
+        V22: 22,
+        V23: 23,
+        V24: 24,
+        V25: 25,
+        V26: 26,
+        V27: 27,
+        V28: 28,
+        V29: 29,
+        V30: 30,
+        V31: 31,
+        V32: 32,
+        V33: 33,
+        V34: 34,
+        V35: 35,
+        V36: 36,
+        V37: 37,
+        V38: 38,
+        V39: 39,
+        V40: 40,
+        V41: 41,
+        V42: 42,
+        V43: 43,
+        V44: 44,
+        V45: 45,
+        V46: 46,
+        V47: 47,
+        V48: 48,
+        V49: 49,
+        V50: 50,
+        V51: 51,
+        V52: 52,
+        V53: 53,
+        V54: 54,
+        V55: 55,
+        V56: 56,
+        V57: 57,
+        V58: 58,
+        V59: 59,
+        V60: 60,
+        V61: 61,
+        V62: 62,
+        V63: 63,
+        V64: 64,
+        V65: 65,
+        V66: 66,
+        V67: 67,
+        V68: 68,
+        V69: 69,
+        V70: 70,
+        V71: 71,
+        V72: 72,
+        V73: 73,
+        V74: 74,
+        V75: 75,
+        V76: 76,
+        V77: 77,
+        V78: 78,
+        V79: 79,
+        V80: 80,
+        V81: 81,
+        V82: 82,
+        V83: 83,
+        V84: 84,
+        V85: 85,
+        V86: 86,
+        V87: 87,
+        V88: 88,
+        V89: 89,
+        V90: 90,
+        V91: 91,
+        V92: 92,
+        V93: 93,
+        V94: 94,
+        V95: 95,
+        V96: 96,
+        V97: 97,
+        V98: 98,
+        V99: 99,
+        V100: 100,
+        V101: 101,
+        V102: 102,
+        V103: 103,
+        V104: 104,
+        V105: 105,
+        V106: 106,
+        V107: 107,
+        V108: 108,
+        V109: 109,
+        V110: 110,
+        V111: 111,
+        V112: 112,
+        V113: 113,
+        V114: 114,
+        V115: 115,
+        V116: 116,
+        V117: 117,
+        V118: 118,
+        V119: 119,
+        V120: 120,
+        V121: 121,
+        V122: 122,
+        V123: 123,
+        V124: 124,
+        V125: 125,
+        V126: 126,
+        V127: 127,
+        V128: 128,
+        V129: 129,
+        V130: 130,
+        V131: 131,
+        V132: 132,
+        V133: 133,
+        V134: 134,
+        V135: 135,
+        V136: 136,
+        V137: 137,
+        V138: 138,
+        V139: 139,
+        V140: 140,
+        V141: 141,
+        V142: 142,
+        V143: 143,
+        V144: 144,
+        V145: 145,
+        V146: 146,
+        V147: 147,
+        V148: 148,
+        V149: 149,
+        V150: 150,
+        V151: 151,
+        V152: 152,
+        V153: 153,
+        V154: 154,
+        V155: 155,
+        V156: 156,
+        V157: 157,
+        V158: 158,
+        V159: 159,
+        V160: 160,
+        V161: 161,
+        V162: 162,
+        V163: 163,
+        V164: 164,
+        V165: 165,
+        V166: 166,
+        V167: 167,
+        V168: 168,
+        V169: 169,
+        V170: 170,
+        V171: 171,
+        V172: 172,
+        V173: 173,
+        V174: 174,
+        V175: 175,
+        V176: 176,
+        V177: 177,
+        V178: 178,
+        V179: 179,
+        V180: 180,
+        V181: 181,
+        V182: 182,
+        V183: 183,
+        V184: 184,
+        V185: 185,
+        V186: 186,
+        V187: 187,
+        V188: 188,
+        V189: 189,
+        V190: 190,
+        V191: 191,
+        V192: 192,
+        V193: 193,
+        V194: 194,
+        V195: 195,
+        V196: 196,
+        V197: 197,
+        V198: 198,
+        V199: 199,
+    }
+}
+
+pub struct Insn {
+    pub opcode: u8,
+}
+
+const_enum! {
+    pub Opcode [Insn::opcode: u8] {
+        Op0: 0,
+        Op1: 1,
+        Op2: 2,
+        Op3: 3,
+        Op4: 4,
+        Op5: 5,
+        Op6: 6,
+        Op7: 7,
+        Op8: 8,
+        Op9: 9,
+        Op10: 10,
+        Op11: 11,
+        Op12: 12,
+        Op13: 13,
+        Op14: 14,
+        Op15: 15,
+        Op16: 16,
+        Op17: 17,
+        Op18: 18,
+        Op19: 19,
+        Op20: 20,
+        Op21: 21,
+        Op22: 22,
+        Op23: 23,
+        Op24: 24,
+        Op25: 25,
+        Op26: 26,
+        Op27: 27,
+        Op28: 28,
+        Op29: 29,
+        Op30: 30,
+        Op31: 31,
+        Op32: 32,
+        Op33: 33,
+        Op34: 34,
+        Op35: 35,
+        Op36: 36,
+        Op37: 37,
+        Op38: 38,
+        Op39: 39,
+        Op40: 40,
+        Op41: 41,
+        Op42: 42,
+        Op43: 43,
+        Op44: 44,
+        Op45: 45,
+        Op46: 46,
+        Op47: 47,
+        Op48: 48,
+        Op49: 49,
+        Op50: 50,
+        Op51: 51,
+        Op52: 52,
+        Op53: 53,
+        Op54: 54,
+        Op55: 55,
+        Op56: 56,
+        Op57: 57,
+        Op58: 58,
+        Op59: 59,
+        Op60: 60,
+        Op61: 61,
+        Op62: 62,
+        Op63: 63,
+        Op64: 64,
+        Op65: 65,
+        Op66: 66,
+        Op67: 67,
+        Op68: 68,
+        Op69: 69,
+        Op70: 70,
+        Op71: 71,
+        Op72: 72,
+        Op73: 73,
+        Op74: 74,
+        Op75: 75,
+        Op76: 76,
+        Op77: 77,
+        Op78: 78,
+        Op79: 79,
+        Op80: 80,
+        Op81: 81,
+        Op82: 82,
+        Op83: 83,
+        Op84: 84,
+        Op85: 85,
+        Op86: 86,
+        Op87: 87,
+        Op88: 88,
+        Op89: 89,
+        Op90: 90,
+        Op91: 91,
+        Op92: 92,
+        Op93: 93,
+        Op94: 94,
+        Op95: 95,
+        Op96: 96,
+        Op97: 97,
+        Op98: 98,
+        Op99: 99,
+        Op100: 100,
+        Op101: 101,
+        Op102: 102,
+        Op103: 103,
+        Op104: 104,
+        Op105: 105,
+        Op106: 106,
+        Op107: 107,
+        Op108: 108,
+        Op109: 109,
+        Op110: 110,
+        Op111: 111,
+        Op112: 112,
+        Op113: 113,
+        Op114: 114,
+        Op115: 115,
+        Op116: 116,
+        Op117: 117,
+        Op118: 118,
+        Op119: 119,
+        Op120: 120,
+        Op121: 121,
+        Op122: 122,
+        Op123: 123,
+        Op124: 124,
+        Op125: 125,
+        Op126: 126,
+        Op127: 127,
+        Op128: 128,
+        Op129: 129,
+        Op130: 130,
+        Op131: 131,
+        Op132: 132,
+        Op133: 133,
+        Op134: 134,
+        Op135: 135,
+        Op136: 136,
+        Op137: 137,
+        Op138: 138,
+        Op139: 139,
+        Op140: 140,
+        Op141: 141,
+        Op142: 142,
+        Op143: 143,
+        Op144: 144,
+        Op145: 145,
+        Op146: 146,
+        Op147: 147,
+        Op148: 148,
+        Op149: 149,
+        Op150: 150,
+        Op151: 151,
+        Op152: 152,
+        Op153: 153,
+        Op154: 154,
+        Op155: 155,
+        Op156: 156,
+        Op157: 157,
+        Op158: 158,
+        Op159: 159,
+        Op160: 160,
+        Op161: 161,
+        Op162: 162,
+        Op163: 163,
+        Op164: 164,
+        Op165: 165,
+        Op166: 166,
+        Op167: 167,
+        Op168: 168,
+        Op169: 169,
+        Op170: 170,
+        Op171: 171,
+        Op172: 172,
+        Op173: 173,
+        Op174: 174,
+        Op175: 175,
+        Op176: 176,
+        Op177: 177,
+        Op178: 178,
+        Op179: 179,
+        Op180: 180,
+        Op181: 181,
+        Op182: 182,
+        Op183: 183,
+        Op184: 184,
+        Op185: 185,
+        Op186: 186,
+        Op187: 187,
+        Op188: 188,
+        Op189: 189,
+        Op190: 190,
+        Op191: 191,
+        Op192: 192,
+        Op193: 193,
+        Op194: 194,
+        Op195: 195,
+        Op196: 196,
+        Op197: 197,
+        Op198: 198,
+        Op199: 199,
+        #[deprecated]
+        Halt = Op0,
+        Vendor(u8): 200..=0xFF,
+    }
+}
+
+#[test]
+fn wide_enum_with_attributes() {
+    assert_eq!(Code::COUNT, 200);
+    assert_eq!(Wide { code: 199 }.as_enum(), Wellknown(Code::V199));
+    assert_eq!(Wide { code: 250 }.as_enum_or_default(), Code::V0);
+}
+
+#[test]
+fn mixed_aliases_and_ranges() {
+    assert_eq!(Opcode::COUNT, 200);
+    assert_eq!(Insn { opcode: 0 }.as_enum(), Wellknown(Opcode::Op0));
+    assert_eq!(Insn { opcode: 199 }.as_enum(), Wellknown(Opcode::Op199));
+    assert_eq!(
+        Insn { opcode: 0xF0 }.as_enum(),
+        Wellknown(Opcode::Vendor(0xF0))
+    );
+}